@@ -1,48 +1,231 @@
-//! This module contains the type aliases for functions called as validators
-//! of a given input.
+//! This module contains the traits that define validators of a given input,
+//! along with the [ValidationError] type they return.
 //!
 //! It also provides several built-in validators generated through macros,
 //! exported with the `builtin_validators` feature.
 
+use std::collections::HashMap;
+use std::fmt;
+
 use crate::answer::OptionAnswer;
 
-/// Type alias for validators that receive a string slice as the input,
-/// such as [Text](crate::Text) and [Password](crate::Password).
-/// When creating containers of validators, you might need to type hint
-/// them using this type.
-///
-/// If the input provided by the user is invalid, your validator should return [Ok(())].
-///
-/// If the input is not valid, your validator should return [Err(String)],
-/// where the content of [Err] is a string whose content will be displayed
-/// to the user as an error message. It is recommended that this value gives
-/// a helpful feedback to the user, e.g. "Your password should contain at least 8 characters".
-pub type StringValidator<'a> = &'a dyn Fn(&str) -> Result<(), String>;
+/// Re-export of [regex::Regex] so the [regex!](crate::regex) macro can refer to
+/// it through `$crate` and keep working in downstream crates that invoke it
+/// without depending on the `regex` crate directly.
+#[cfg(feature = "regex")]
+#[doc(hidden)]
+pub use regex::Regex;
+
+/// Error returned by a validator when the input is rejected.
+///
+/// Besides the human-facing `message` that is rendered to the user, a
+/// `ValidationError` carries a machine-readable `code` (e.g. `"min_length"`,
+/// `"max_length"`, `"parse"`) and a map of `params` describing the failure
+/// (e.g. `min`/`max`/`actual`). This lets callers localize messages or tell a
+/// length failure apart from a parse failure without inspecting the rendered
+/// string.
+///
+/// The built-in validators populate `code` and `params` automatically while
+/// keeping the rendered message identical to the one they produced before.
+/// Plain [String] and `&str` errors are still accepted through the [From]
+/// impls, in which case they become the `message` with a `"custom"` code.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationError {
+    code: String,
+    params: HashMap<String, String>,
+    message: Option<String>,
+}
+
+impl ValidationError {
+    /// Creates a new error with the given machine-readable code and no params
+    /// or message.
+    pub fn new(code: impl Into<String>) -> Self {
+        Self {
+            code: code.into(),
+            params: HashMap::new(),
+            message: None,
+        }
+    }
+
+    /// Adds a parameter describing the failure, e.g. `min`/`max`/`actual`.
+    pub fn with_param(mut self, key: impl Into<String>, value: impl ToString) -> Self {
+        self.params.insert(key.into(), value.to_string());
+        self
+    }
+
+    /// Sets the human-facing message rendered to the user.
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// The machine-readable code identifying the kind of failure.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// The parameters describing the failure.
+    pub fn params(&self) -> &HashMap<String, String> {
+        &self.params
+    }
+
+    /// The human-facing message, if one was set.
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.message {
+            Some(message) => write!(f, "{}", message),
+            None => write!(f, "{}", self.code),
+        }
+    }
+}
 
-/// Type alias for validators used in [DateSelect](crate::DateSelect) prompts.
-/// When creating containers of validators, you might need to type hint
-/// them using this type.
+impl From<String> for ValidationError {
+    fn from(message: String) -> Self {
+        Self {
+            code: String::from("custom"),
+            params: HashMap::new(),
+            message: Some(message),
+        }
+    }
+}
+
+impl From<&str> for ValidationError {
+    fn from(message: &str) -> Self {
+        Self::from(String::from(message))
+    }
+}
+
+/// Validator for inputs received as a string slice, such as
+/// [Text](crate::Text) and [Password](crate::Password) prompts.
+///
+/// Implement this trait to write stateful or reusable validators, or compose
+/// several of them together. A blanket implementation is provided for every
+/// `Fn(&str) -> Result<(), E>` where `E: Into<ValidationError>`, so plain
+/// closures returning a [String] or a [ValidationError] keep working wherever a
+/// `StringValidator` is expected.
+///
+/// If the input provided by the user is valid, your validator should return [Ok(())].
+///
+/// If the input is not valid, your validator should return [Err], carrying a
+/// [ValidationError] whose rendered message will be displayed to the user. It
+/// is recommended that this value gives a helpful feedback to the user, e.g.
+/// "Your password should contain at least 8 characters".
+pub trait StringValidator {
+    /// Validates the input, returning [Ok(())] when it is accepted and
+    /// [Err] with a [ValidationError] otherwise.
+    fn validate(&self, input: &str) -> Result<(), ValidationError>;
+}
+
+impl<F, E> StringValidator for F
+where
+    F: Fn(&str) -> Result<(), E>,
+    E: Into<ValidationError>,
+{
+    fn validate(&self, input: &str) -> Result<(), ValidationError> {
+        (self)(input).map_err(Into::into)
+    }
+}
+
+/// Validator for [DateSelect](crate::DateSelect) prompts.
+///
+/// Implement this trait to write stateful or reusable validators, or compose
+/// several of them together. A blanket implementation is provided for every
+/// `Fn(chrono::NaiveDate) -> Result<(), E>` where `E: Into<ValidationError>`,
+/// so plain closures returning a [String] or a [ValidationError] keep working
+/// wherever a `DateValidator` is expected.
 ///
-/// If the input provided by the user is invalid, your validator should return [Ok(())].
+/// If the input provided by the user is valid, your validator should return [Ok(())].
 ///
-/// If the input is not valid, your validator should return [Err(String)],
-/// where the content of [Err] is a string whose content will be displayed
-/// to the user as an error message. It is recommended that this value gives
-/// a helpful feedback to the user, e.g. "Setting your appointment on Saturdays is not allowed".
+/// If the input is not valid, your validator should return [Err], carrying a
+/// [ValidationError] whose rendered message will be displayed to the user. It
+/// is recommended that this value gives a helpful feedback to the user, e.g.
+/// "Setting your appointment on Saturdays is not allowed".
 #[cfg(feature = "date")]
-pub type DateValidator<'a> = &'a dyn Fn(chrono::NaiveDate) -> Result<(), String>;
+pub trait DateValidator {
+    /// Validates the selected date, returning [Ok(())] when it is accepted and
+    /// [Err] with a [ValidationError] otherwise.
+    fn validate(&self, input: chrono::NaiveDate) -> Result<(), ValidationError>;
+}
 
-/// Type alias for validators used in [MultiSelect](crate::MultiSelect) prompts.
-/// When creating containers of validators, you might need to type hint
-/// them using this type.
-///
-/// If the input provided by the user is invalid, your validator should return [Ok(())].
+#[cfg(feature = "date")]
+impl<F, E> DateValidator for F
+where
+    F: Fn(chrono::NaiveDate) -> Result<(), E>,
+    E: Into<ValidationError>,
+{
+    fn validate(&self, input: chrono::NaiveDate) -> Result<(), ValidationError> {
+        (self)(input).map_err(Into::into)
+    }
+}
+
+/// Validator for [MultiSelect](crate::MultiSelect) prompts.
+///
+/// Implement this trait to write stateful or reusable validators, or compose
+/// several of them together. A blanket implementation is provided for every
+/// `Fn(&[OptionAnswer]) -> Result<(), E>` where `E: Into<ValidationError>`, so
+/// plain closures returning a [String] or a [ValidationError] keep working
+/// wherever a `MultiOptionValidator` is expected.
+///
+/// If the input provided by the user is valid, your validator should return [Ok(())].
+///
+/// If the input is not valid, your validator should return [Err], carrying a
+/// [ValidationError] whose rendered message will be displayed to the user. It
+/// is recommended that this value gives a helpful feedback to the user, e.g.
+/// "You should select at most two options".
+pub trait MultiOptionValidator {
+    /// Validates the selected options, returning [Ok(())] when they are
+    /// accepted and [Err] with a [ValidationError] otherwise.
+    fn validate(&self, input: &[OptionAnswer]) -> Result<(), ValidationError>;
+}
+
+impl<F, E> MultiOptionValidator for F
+where
+    F: Fn(&[OptionAnswer]) -> Result<(), E>,
+    E: Into<ValidationError>,
+{
+    fn validate(&self, input: &[OptionAnswer]) -> Result<(), ValidationError> {
+        (self)(input).map_err(Into::into)
+    }
+}
+
+/// Counts the grapheme clusters in `input`, used by the `graphemes` counting
+/// mode of the length validators. Only available with the
+/// `unicode-segmentation` feature, which pulls in the crate of the same name.
+#[cfg(feature = "unicode-segmentation")]
+pub fn grapheme_count(input: &str) -> usize {
+    use unicode_segmentation::UnicodeSegmentation;
+
+    input.graphemes(true).count()
+}
+
+/// Internal helper that expands to the length of `$a` counted according to the
+/// requested `unit`. Not part of the public API.
 ///
-/// If the input is not valid, your validator should return [Err(String)],
-/// where the content of [Err] is a string whose content will be displayed
-/// to the user as an error message. It is recommended that this value gives
-/// a helpful feedback to the user, e.g. "You should select at most two options".
-pub type MultiOptionValidator<'a> = &'a dyn Fn(&[OptionAnswer]) -> Result<(), String>;
+/// * `chars` counts Unicode scalar values, matching what users visually expect.
+/// * `utf16` counts UTF-16 code units, matching JavaScript's `String.length`.
+/// * `graphemes` counts grapheme clusters (requires the `unicode-segmentation`
+///   feature).
+#[macro_export]
+#[doc(hidden)]
+#[cfg(feature = "builtin_validators")]
+macro_rules! __inquire_str_count {
+    ($a:expr, chars) => {
+        $a.chars().count()
+    };
+
+    ($a:expr, utf16) => {
+        $a.encode_utf16().count()
+    };
+
+    ($a:expr, graphemes) => {
+        $crate::validator::grapheme_count($a)
+    };
+}
 
 /// Built-in validator that checks whether the answer is not empty.
 ///
@@ -56,13 +239,13 @@ pub type MultiOptionValidator<'a> = &'a dyn Fn(&[OptionAnswer]) -> Result<(), St
 /// ```
 /// use inquire::{required, validator::StringValidator};
 ///
-/// let validator: StringValidator = required!();
-/// assert_eq!(Ok(()), validator("Generic input"));
-/// assert_eq!(Err(String::from("A response is required.")), validator(""));
+/// let validator = required!();
+/// assert_eq!(Ok(()), validator.validate("Generic input"));
+/// assert_eq!(Err(String::from("A response is required.")), validator.validate("").map_err(|e| e.to_string()));
 ///
-/// let validator: StringValidator = required!("No empty!");
-/// assert_eq!(Ok(()), validator("Generic input"));
-/// assert_eq!(Err(String::from("No empty!")), validator(""));
+/// let validator = required!("No empty!");
+/// assert_eq!(Ok(()), validator.validate("Generic input"));
+/// assert_eq!(Err(String::from("No empty!")), validator.validate("").map_err(|e| e.to_string()));
 /// ```
 #[macro_export]
 #[cfg(feature = "builtin_validators")]
@@ -72,8 +255,8 @@ macro_rules! required {
     };
 
     ($message:expr) => {
-        &|a| match a.is_empty() {
-            true => Err(String::from($message)),
+        &|a: &str| match a.is_empty() {
+            true => Err($crate::validator::ValidationError::new("required").with_message($message)),
             false => Ok(()),
         }
     };
@@ -82,13 +265,17 @@ macro_rules! required {
 /// Built-in validator that checks whether the answer length is smaller than
 /// or equal to the specified threshold.
 ///
-/// Be careful when using this as a StringValidator. The `len()` method used
-/// in this validator is not the best tool for that. See this
-/// [StackOverflow question](https://stackoverflow.com/questions/46290655/get-the-string-length-in-characters-in-rust)
+/// By default the length is the number of Unicode scalar values in the input
+/// (`chars().count()`), so multibyte input such as "café" behaves the way a
+/// user visually expects. Pass `unit = utf16` to count UTF-16 code units (e.g.
+/// to match JavaScript's `String.length`) or `unit = graphemes` to count
+/// grapheme clusters (requires the `unicode-segmentation` feature).
 ///
 /// # Arguments
 ///
 /// * `$length` - Maximum length of the input.
+/// * `unit = $unit` - optional - Counting mode: `chars` (default), `utf16` or
+///   `graphemes`.
 /// * `$message` - optional - Error message returned by the validator.
 ///   Defaults to "The length of the response should be at most $length"
 ///
@@ -97,42 +284,57 @@ macro_rules! required {
 /// ```
 /// use inquire::{max_length, validator::StringValidator};
 ///
-/// let validator: StringValidator = max_length!(5);
-/// assert_eq!(Ok(()), validator("Good"));
-/// assert_eq!(Err(String::from("The length of the response should be at most 5")), validator("Terrible"));
+/// let validator = max_length!(5);
+/// assert_eq!(Ok(()), validator.validate("Good"));
+/// assert_eq!(Err(String::from("The length of the response should be at most 5")), validator.validate("Terrible").map_err(|e| e.to_string()));
+///
+/// let validator = max_length!(4);
+/// assert_eq!(Ok(()), validator.validate("café"));
 ///
-/// let validator: StringValidator = max_length!(5, "Not too large!");
-/// assert_eq!(Ok(()), validator("Good"));
-/// assert_eq!(Err(String::from("Not too large!")), validator("Terrible"));
+/// let validator = max_length!(5, "Not too large!");
+/// assert_eq!(Ok(()), validator.validate("Good"));
+/// assert_eq!(Err(String::from("Not too large!")), validator.validate("Terrible").map_err(|e| e.to_string()));
 /// ```
 #[macro_export]
 #[cfg(feature = "builtin_validators")]
 macro_rules! max_length {
     ($length:expr) => {
-        $crate::max_length! {$length, format!("The length of the response should be at most {}", $length)}
+        $crate::max_length! {$length, unit = chars}
+    };
+
+    ($length:expr, unit = $unit:ident) => {
+        $crate::max_length! {$length, unit = $unit, format!("The length of the response should be at most {}", $length)}
     };
 
     ($length:expr, $message:expr) => {
-        {
-            &|a| match a.len() {
-                _len if _len <= $length => Ok(()),
-                _ => Err(String::from($message)),
-            }
+        $crate::max_length! {$length, unit = chars, $message}
+    };
 
+    ($length:expr, unit = $unit:ident, $message:expr) => {{
+        &|a: &str| match $crate::__inquire_str_count!(a, $unit) {
+            _len if _len <= $length => Ok(()),
+            _len => Err($crate::validator::ValidationError::new("max_length")
+                .with_param("max", $length)
+                .with_param("actual", _len)
+                .with_message($message)),
         }
-    };
+    }};
 }
 
 /// Built-in validator that checks whether the answer length is larger than
 /// or equal to the specified threshold.
 ///
-/// Be careful when using this as a StringValidator. The `len()` method used
-/// in this validator is not the best tool for that. See this
-/// [StackOverflow question](https://stackoverflow.com/questions/46290655/get-the-string-length-in-characters-in-rust)
+/// By default the length is the number of Unicode scalar values in the input
+/// (`chars().count()`), so multibyte input such as "café" behaves the way a
+/// user visually expects. Pass `unit = utf16` to count UTF-16 code units (e.g.
+/// to match JavaScript's `String.length`) or `unit = graphemes` to count
+/// grapheme clusters (requires the `unicode-segmentation` feature).
 ///
 /// # Arguments
 ///
 /// * `$length` - Minimum length of the input.
+/// * `unit = $unit` - optional - Counting mode: `chars` (default), `utf16` or
+///   `graphemes`.
 /// * `$message` - optional - Error message returned by the validator.
 ///   Defaults to "The length of the response should be at least $length"
 ///
@@ -141,41 +343,54 @@ macro_rules! max_length {
 /// ```
 /// use inquire::{min_length, validator::StringValidator};
 ///
-/// let validator: StringValidator = min_length!(3);
-/// assert_eq!(Ok(()), validator("Yes"));
-/// assert_eq!(Err(String::from("The length of the response should be at least 3")), validator("No"));
+/// let validator = min_length!(3);
+/// assert_eq!(Ok(()), validator.validate("Yes"));
+/// assert_eq!(Err(String::from("The length of the response should be at least 3")), validator.validate("No").map_err(|e| e.to_string()));
 ///
-/// let validator: StringValidator = min_length!(3, "You have to give me more than that!");
-/// assert_eq!(Ok(()), validator("Yes"));
-/// assert_eq!(Err(String::from("You have to give me more than that!")), validator("No"));
+/// let validator = min_length!(3, "You have to give me more than that!");
+/// assert_eq!(Ok(()), validator.validate("Yes"));
+/// assert_eq!(Err(String::from("You have to give me more than that!")), validator.validate("No").map_err(|e| e.to_string()));
 /// ```
 #[macro_export]
 #[cfg(feature = "builtin_validators")]
 macro_rules! min_length {
     ($length:expr) => {
-        $crate::min_length! {$length, format!("The length of the response should be at least {}", $length)}
+        $crate::min_length! {$length, unit = chars}
+    };
+
+    ($length:expr, unit = $unit:ident) => {
+        $crate::min_length! {$length, unit = $unit, format!("The length of the response should be at least {}", $length)}
     };
 
     ($length:expr, $message:expr) => {
-        {
-            &|a| match a.len() {
-                _len if _len >= $length => Ok(()),
-                _ => Err(String::from($message)),
-            }
-        }
+        $crate::min_length! {$length, unit = chars, $message}
     };
+
+    ($length:expr, unit = $unit:ident, $message:expr) => {{
+        &|a: &str| match $crate::__inquire_str_count!(a, $unit) {
+            _len if _len >= $length => Ok(()),
+            _len => Err($crate::validator::ValidationError::new("min_length")
+                .with_param("min", $length)
+                .with_param("actual", _len)
+                .with_message($message)),
+        }
+    }};
 }
 
 /// Built-in validator that checks whether the answer length is equal to
 /// the specified value.
 ///
-/// Be careful when using this as a StringValidator. The `len()` method used
-/// in this validator is not the best tool for that. See this
-/// [StackOverflow question](https://stackoverflow.com/questions/46290655/get-the-string-length-in-characters-in-rust)
+/// By default the length is the number of Unicode scalar values in the input
+/// (`chars().count()`), so multibyte input such as "café" behaves the way a
+/// user visually expects. Pass `unit = utf16` to count UTF-16 code units (e.g.
+/// to match JavaScript's `String.length`) or `unit = graphemes` to count
+/// grapheme clusters (requires the `unicode-segmentation` feature).
 ///
 /// # Arguments
 ///
 /// * `$length` - Expected length of the input.
+/// * `unit = $unit` - optional - Counting mode: `chars` (default), `utf16` or
+///   `graphemes`.
 /// * `$message` - optional - Error message returned by the validator.
 ///   Defaults to "The length of the response should be $length"
 ///
@@ -184,25 +399,36 @@ macro_rules! min_length {
 /// ```
 /// use inquire::{length, validator::StringValidator};
 ///
-/// let validator: StringValidator = length!(3);
-/// assert_eq!(Ok(()), validator("Yes"));
-/// assert_eq!(Err(String::from("The length of the response should be 3")), validator("No"));
+/// let validator = length!(3);
+/// assert_eq!(Ok(()), validator.validate("Yes"));
+/// assert_eq!(Err(String::from("The length of the response should be 3")), validator.validate("No").map_err(|e| e.to_string()));
 ///
-/// let validator: StringValidator = length!(3, "Three characters please.");
-/// assert_eq!(Ok(()), validator("Yes"));
-/// assert_eq!(Err(String::from("Three characters please.")), validator("No"));
+/// let validator = length!(3, "Three characters please.");
+/// assert_eq!(Ok(()), validator.validate("Yes"));
+/// assert_eq!(Err(String::from("Three characters please.")), validator.validate("No").map_err(|e| e.to_string()));
 /// ```
 #[macro_export]
 #[cfg(feature = "builtin_validators")]
 macro_rules! length {
     ($length:expr) => {
-        $crate::length! {$length, format!("The length of the response should be {}", $length)}
+        $crate::length! {$length, unit = chars}
+    };
+
+    ($length:expr, unit = $unit:ident) => {
+        $crate::length! {$length, unit = $unit, format!("The length of the response should be {}", $length)}
     };
 
-    ($length:expr, $message:expr) => {{
-        &|a| match a.len() {
+    ($length:expr, $message:expr) => {
+        $crate::length! {$length, unit = chars, $message}
+    };
+
+    ($length:expr, unit = $unit:ident, $message:expr) => {{
+        &|a: &str| match $crate::__inquire_str_count!(a, $unit) {
             _len if _len == $length => Ok(()),
-            _ => Err(String::from($message)),
+            _len => Err($crate::validator::ValidationError::new("length")
+                .with_param("length", $length)
+                .with_param("actual", _len)
+                .with_message($message)),
         }
     }};
 }
@@ -222,13 +448,13 @@ macro_rules! length {
 /// ```
 /// use inquire::{parse_primitive, validator::StringValidator};
 ///
-/// let validator: StringValidator = parse_primitive!(f64);
-/// assert_eq!(Ok(()), validator("32.44"));
-/// assert_eq!(Err(String::from("Failure when parsing response to type f64")), validator("32f"));
+/// let validator = parse_primitive!(f64);
+/// assert_eq!(Ok(()), validator.validate("32.44"));
+/// assert_eq!(Err(String::from("Failure when parsing response to type f64")), validator.validate("32f").map_err(|e| e.to_string()));
 ///
-/// let validator: StringValidator = parse_primitive!(f64, "Invalid number");
-/// assert_eq!(Ok(()), validator("11e15"));
-/// assert_eq!(Err(String::from("Invalid number")), validator("11^2"));
+/// let validator = parse_primitive!(f64, "Invalid number");
+/// assert_eq!(Ok(()), validator.validate("11e15"));
+/// assert_eq!(Err(String::from("Invalid number")), validator.validate("11^2").map_err(|e| e.to_string()));
 /// ```
 #[macro_export]
 #[cfg(feature = "builtin_validators")]
@@ -238,9 +464,430 @@ macro_rules! parse_primitive {
     };
 
     ($type:ty, $message:expr) => {{
-        &|a| match a.parse::<$type>() {
+        &|a: &str| match a.parse::<$type>() {
             Ok(_) => Ok(()),
-            Err(err) => Err(String::from($message)),
+            Err(_) => Err($crate::validator::ValidationError::new("parse")
+                .with_param("type", std::any::type_name::<$type>())
+                .with_message($message)),
+        }
+    }};
+}
+
+/// Built-in validator that checks whether the answer matches a regular
+/// expression, useful to enforce formats such as emails, slugs or phone
+/// numbers directly from a [Text](crate::Text) or [Password](crate::Password)
+/// prompt.
+///
+/// The pattern is compiled only once, the first time the validator runs, and
+/// cached in a [OnceLock](std::sync::OnceLock) so it is not recompiled on every
+/// keystroke during live validation. If `$pattern` is not a valid regular
+/// expression it is not compiled on every keystroke either: the failed compile
+/// is cached and the validator rejects every input with a `"regex_compile"`
+/// coded error instead of panicking mid-prompt.
+///
+/// Requires the `regex` feature, which pulls in the [regex](https://crates.io/crates/regex)
+/// crate.
+///
+/// # Arguments
+///
+/// * `$pattern` - Regular expression the input must match.
+/// * `$message` - optional - Error message returned by the validator.
+///   Defaults to "The response should match the pattern $pattern"
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{regex, validator::StringValidator};
+///
+/// let validator = regex!(r"^\S+@\S+\.\S+$", "Enter a valid email");
+/// assert_eq!(Ok(()), validator.validate("foo@example.com"));
+/// assert_eq!(Err(String::from("Enter a valid email")), validator.validate("not-an-email").map_err(|e| e.to_string()));
+/// ```
+#[macro_export]
+#[cfg(all(feature = "builtin_validators", feature = "regex"))]
+macro_rules! regex {
+    ($pattern:expr) => {
+        $crate::regex! {$pattern, format!("The response should match the pattern {}", $pattern)}
+    };
+
+    ($pattern:expr, $message:expr) => {{
+        static RE: std::sync::OnceLock<Option<$crate::validator::Regex>> =
+            std::sync::OnceLock::new();
+        &|a: &str| match RE.get_or_init(|| $crate::validator::Regex::new($pattern).ok()) {
+            Some(re) if re.is_match(a) => Ok(()),
+            Some(_) => Err($crate::validator::ValidationError::new("regex")
+                .with_param("pattern", $pattern)
+                .with_message($message)),
+            None => Err($crate::validator::ValidationError::new("regex_compile")
+                .with_param("pattern", $pattern)
+                .with_message(format!(
+                    "The pattern {} is not a valid regular expression",
+                    $pattern
+                ))),
+        }
+    }};
+}
+
+/// Built-in validator that parses the answer to a given type and checks whether
+/// it falls within an inclusive range, e.g. `in_range!(i64, 1..=10)`.
+///
+/// The input is first parsed with the same logic as [parse_primitive!], so a
+/// value that cannot be parsed to `$type` is rejected as well.
+///
+/// # Arguments
+///
+/// * `$type` - Target type of the parsing operation.
+/// * `$range` - Inclusive range (`lo..=hi`) the parsed value must fall within.
+/// * `$message` - optional - Error message returned by the validator when the
+///   parsed value is out of range; an unparsable input always gets the
+///   "Failure when parsing response to type $type" message.
+///   Defaults to "The number should be between $lo and $hi"
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{in_range, validator::StringValidator};
+///
+/// let validator = in_range!(i64, 1..=10);
+/// assert_eq!(Ok(()), validator.validate("7"));
+/// assert_eq!(Err(String::from("The number should be between 1 and 10")), validator.validate("42").map_err(|e| e.to_string()));
+/// ```
+#[macro_export]
+#[cfg(feature = "builtin_validators")]
+macro_rules! in_range {
+    ($type:ty, $range:expr) => {
+        $crate::in_range! {$type, $range, format!("The number should be between {} and {}", $range.start(), $range.end())}
+    };
+
+    ($type:ty, $range:expr, $message:expr) => {{
+        &|a: &str| match a.parse::<$type>() {
+            Err(_) => Err($crate::validator::ValidationError::new("parse")
+                .with_param("type", std::any::type_name::<$type>())
+                .with_message(format!(
+                    "Failure when parsing response to type {}",
+                    std::any::type_name::<$type>()
+                ))),
+            Ok(_value) if $range.contains(&_value) => Ok(()),
+            Ok(_) => Err($crate::validator::ValidationError::new("in_range")
+                .with_param("min", $range.start())
+                .with_param("max", $range.end())
+                .with_message($message)),
+        }
+    }};
+}
+
+/// Built-in validator that parses the answer to a given type and checks whether
+/// it is strictly greater than a value, e.g. `greater_than!(i64, 0)`.
+///
+/// The input is first parsed with the same logic as [parse_primitive!], so a
+/// value that cannot be parsed to `$type` is rejected as well.
+///
+/// # Arguments
+///
+/// * `$type` - Target type of the parsing operation.
+/// * `$value` - Value the parsed input must be greater than.
+/// * `$message` - optional - Error message returned by the validator when the
+///   parsed value is not greater than `$value`; an unparsable input always
+///   gets the "Failure when parsing response to type $type" message.
+///   Defaults to "The number should be greater than $value"
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{greater_than, validator::StringValidator};
+///
+/// let validator = greater_than!(i64, 0);
+/// assert_eq!(Ok(()), validator.validate("5"));
+/// assert_eq!(Err(String::from("The number should be greater than 0")), validator.validate("-1").map_err(|e| e.to_string()));
+/// ```
+#[macro_export]
+#[cfg(feature = "builtin_validators")]
+macro_rules! greater_than {
+    ($type:ty, $value:expr) => {
+        $crate::greater_than! {$type, $value, format!("The number should be greater than {}", $value)}
+    };
+
+    ($type:ty, $value:expr, $message:expr) => {{
+        &|a: &str| match a.parse::<$type>() {
+            Err(_) => Err($crate::validator::ValidationError::new("parse")
+                .with_param("type", std::any::type_name::<$type>())
+                .with_message(format!(
+                    "Failure when parsing response to type {}",
+                    std::any::type_name::<$type>()
+                ))),
+            Ok(_value) if _value > $value => Ok(()),
+            Ok(_) => Err($crate::validator::ValidationError::new("greater_than")
+                .with_param("min", $value)
+                .with_message($message)),
+        }
+    }};
+}
+
+/// Built-in validator that parses the answer to a given type and checks whether
+/// it is strictly less than a value, e.g. `less_than!(i64, 100)`.
+///
+/// The input is first parsed with the same logic as [parse_primitive!], so a
+/// value that cannot be parsed to `$type` is rejected as well.
+///
+/// # Arguments
+///
+/// * `$type` - Target type of the parsing operation.
+/// * `$value` - Value the parsed input must be less than.
+/// * `$message` - optional - Error message returned by the validator when the
+///   parsed value is not less than `$value`; an unparsable input always gets
+///   the "Failure when parsing response to type $type" message.
+///   Defaults to "The number should be less than $value"
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{less_than, validator::StringValidator};
+///
+/// let validator = less_than!(i64, 100);
+/// assert_eq!(Ok(()), validator.validate("42"));
+/// assert_eq!(Err(String::from("The number should be less than 100")), validator.validate("100").map_err(|e| e.to_string()));
+/// ```
+#[macro_export]
+#[cfg(feature = "builtin_validators")]
+macro_rules! less_than {
+    ($type:ty, $value:expr) => {
+        $crate::less_than! {$type, $value, format!("The number should be less than {}", $value)}
+    };
+
+    ($type:ty, $value:expr, $message:expr) => {{
+        &|a: &str| match a.parse::<$type>() {
+            Err(_) => Err($crate::validator::ValidationError::new("parse")
+                .with_param("type", std::any::type_name::<$type>())
+                .with_message(format!(
+                    "Failure when parsing response to type {}",
+                    std::any::type_name::<$type>()
+                ))),
+            Ok(_value) if _value < $value => Ok(()),
+            Ok(_) => Err($crate::validator::ValidationError::new("less_than")
+                .with_param("max", $value)
+                .with_message($message)),
+        }
+    }};
+}
+
+/// Built-in validator for [DateSelect](crate::DateSelect) prompts that checks
+/// whether the selected date falls within an inclusive window.
+///
+/// # Arguments
+///
+/// * `$min` - Earliest accepted date (inclusive).
+/// * `$max` - Latest accepted date (inclusive).
+/// * `$message` - optional - Error message returned by the validator.
+///   Defaults to "The date should be between $min and $max"
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use inquire::{date_in_range, validator::DateValidator};
+///
+/// let min = NaiveDate::from_ymd(2021, 1, 1);
+/// let max = NaiveDate::from_ymd(2021, 12, 31);
+/// let validator = date_in_range!(min, max);
+/// assert_eq!(Ok(()), validator.validate(NaiveDate::from_ymd(2021, 6, 15)));
+/// ```
+#[macro_export]
+#[cfg(all(feature = "builtin_validators", feature = "date"))]
+macro_rules! date_in_range {
+    ($min:expr, $max:expr) => {
+        $crate::date_in_range! {$min, $max, format!("The date should be between {} and {}", $min, $max)}
+    };
+
+    ($min:expr, $max:expr, $message:expr) => {{
+        &|d: chrono::NaiveDate| match d {
+            _d if _d >= $min && _d <= $max => Ok(()),
+            _ => Err($crate::validator::ValidationError::new("date_in_range")
+                .with_param("min", $min)
+                .with_param("max", $max)
+                .with_message($message)),
+        }
+    }};
+}
+
+/// Built-in validator for [DateSelect](crate::DateSelect) prompts that rejects
+/// a selection landing on one of the listed weekdays, e.g.
+/// `weekday_not!(chrono::Weekday::Sat, chrono::Weekday::Sun)`.
+///
+/// # Arguments
+///
+/// * `$day` - One or more [Weekday](chrono::Weekday) values that are not allowed.
+/// * `$message` - optional - Error message returned by the validator, only
+///   available in the bracketed form. Defaults to "The selected weekday is not
+///   allowed".
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+/// use inquire::{weekday_not, validator::DateValidator};
+///
+/// let validator = weekday_not!(Weekday::Sat, Weekday::Sun);
+/// // 2021-08-07 is a Saturday.
+/// assert_eq!(
+///     Err(String::from("The selected weekday is not allowed")),
+///     validator.validate(NaiveDate::from_ymd(2021, 8, 7)).map_err(|e| e.to_string()),
+/// );
+/// ```
+#[macro_export]
+#[cfg(all(feature = "builtin_validators", feature = "date"))]
+macro_rules! weekday_not {
+    ([$($day:expr),+ $(,)?], $message:expr) => {{
+        &|d: chrono::NaiveDate| {
+            use chrono::Datelike;
+            let _wd = d.weekday();
+            match false $(|| _wd == $day)+ {
+                true => Err($crate::validator::ValidationError::new("weekday_not")
+                    .with_param("weekday", _wd)
+                    .with_message($message)),
+                false => Ok(()),
+            }
+        }
+    }};
+
+    ($($day:expr),+ $(,)?) => {
+        $crate::weekday_not! {[$($day),+], "The selected weekday is not allowed"}
+    };
+}
+
+/// Built-in validator that checks whether the answer belongs to an allowed set,
+/// e.g. `one_of!(["yes", "no", "maybe"])`.
+///
+/// # Arguments
+///
+/// * `$item` - The allowed values, as a bracketed list of string slices.
+/// * `$message` - optional - Error message returned by the validator.
+///   Defaults to "The response should be one of: $items"
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{one_of, validator::StringValidator};
+///
+/// let validator = one_of!(["yes", "no", "maybe"]);
+/// assert_eq!(Ok(()), validator.validate("yes"));
+/// assert_eq!(Err(String::from("The response should be one of: yes, no, maybe")), validator.validate("nope").map_err(|e| e.to_string()));
+/// ```
+#[macro_export]
+#[cfg(feature = "builtin_validators")]
+macro_rules! one_of {
+    ([$($item:expr),+ $(,)?]) => {
+        $crate::one_of! {[$($item),+], format!("The response should be one of: {}", [$($item),+].join(", "))}
+    };
+
+    ([$($item:expr),+ $(,)?], $message:expr) => {{
+        &|a: &str| match [$($item),+].iter().any(|_i| *_i == a) {
+            true => Ok(()),
+            false => Err($crate::validator::ValidationError::new("one_of").with_message($message)),
+        }
+    }};
+}
+
+/// Built-in validator for [MultiSelect](crate::MultiSelect) prompts that checks
+/// whether at most `$max` options were selected.
+///
+/// # Arguments
+///
+/// * `$max` - Maximum number of selected options.
+/// * `$message` - optional - Error message returned by the validator.
+///   Defaults to "You should select at most $max options"
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{max_selected, validator::MultiOptionValidator};
+///
+/// let validator = max_selected!(2);
+/// assert_eq!(Ok(()), validator.validate(&[]));
+/// ```
+#[macro_export]
+#[cfg(feature = "builtin_validators")]
+macro_rules! max_selected {
+    ($max:expr) => {
+        $crate::max_selected! {$max, format!("You should select at most {} options", $max)}
+    };
+
+    ($max:expr, $message:expr) => {{
+        &|a: &[$crate::answer::OptionAnswer]| match a.len() {
+            _len if _len <= $max => Ok(()),
+            _len => Err($crate::validator::ValidationError::new("max_selected")
+                .with_param("max", $max)
+                .with_param("actual", _len)
+                .with_message($message)),
+        }
+    }};
+}
+
+/// Built-in validator for [MultiSelect](crate::MultiSelect) prompts that checks
+/// whether at least `$min` options were selected.
+///
+/// # Arguments
+///
+/// * `$min` - Minimum number of selected options.
+/// * `$message` - optional - Error message returned by the validator.
+///   Defaults to "You should select at least $min options"
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{min_selected, validator::MultiOptionValidator};
+///
+/// let validator = min_selected!(1);
+/// assert_eq!(Err(String::from("You should select at least 1 options")), validator.validate(&[]).map_err(|e| e.to_string()));
+/// ```
+#[macro_export]
+#[cfg(feature = "builtin_validators")]
+macro_rules! min_selected {
+    ($min:expr) => {
+        $crate::min_selected! {$min, format!("You should select at least {} options", $min)}
+    };
+
+    ($min:expr, $message:expr) => {{
+        &|a: &[$crate::answer::OptionAnswer]| match a.len() {
+            _len if _len >= $min => Ok(()),
+            _len => Err($crate::validator::ValidationError::new("min_selected")
+                .with_param("min", $min)
+                .with_param("actual", _len)
+                .with_message($message)),
+        }
+    }};
+}
+
+/// Built-in validator for [MultiSelect](crate::MultiSelect) prompts that checks
+/// whether exactly `$count` options were selected.
+///
+/// # Arguments
+///
+/// * `$count` - Expected number of selected options.
+/// * `$message` - optional - Error message returned by the validator.
+///   Defaults to "You should select exactly $count options"
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{exactly_selected, validator::MultiOptionValidator};
+///
+/// let validator = exactly_selected!(2);
+/// assert_eq!(Err(String::from("You should select exactly 2 options")), validator.validate(&[]).map_err(|e| e.to_string()));
+/// ```
+#[macro_export]
+#[cfg(feature = "builtin_validators")]
+macro_rules! exactly_selected {
+    ($count:expr) => {
+        $crate::exactly_selected! {$count, format!("You should select exactly {} options", $count)}
+    };
+
+    ($count:expr, $message:expr) => {{
+        &|a: &[$crate::answer::OptionAnswer]| match a.len() {
+            _len if _len == $count => Ok(()),
+            _len => Err($crate::validator::ValidationError::new("exactly_selected")
+                .with_param("count", $count)
+                .with_param("actual", _len)
+                .with_message($message)),
         }
     }};
 }